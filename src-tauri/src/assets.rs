@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, Semaphore};
+use url::Url;
+
+/// Caches remote background/stock images on disk so covers can be edited
+/// offline and the same asset isn't re-downloaded on every render.
+pub struct Proxy {
+  cache_dir: PathBuf,
+  client: reqwest::Client,
+  inflight: Mutex<HashMap<Url, Arc<Semaphore>>>,
+}
+
+impl Proxy {
+  pub fn new(cache_dir: PathBuf) -> Self {
+    Self {
+      cache_dir,
+      client: reqwest::Client::new(),
+      inflight: Mutex::new(HashMap::new()),
+    }
+  }
+
+  /// Resolves `src` to a local cached file, downloading it first if this is
+  /// the first time it's been seen. Concurrent requests for the same URL
+  /// share a single download via a per-URL semaphore.
+  pub async fn proxy(&self, src: &Url) -> Result<PathBuf, String> {
+    let cache_path = self.cache_path(src);
+
+    if cache_path.exists() {
+      return Ok(cache_path);
+    }
+
+    let semaphore = self.semaphore_for(src).await;
+    let permit = semaphore
+      .acquire()
+      .await
+      .map_err(|error| format!("asset download semaphore closed: {error}"))?;
+
+    // Another task may have finished the download while we waited.
+    let result = if cache_path.exists() {
+      Ok(())
+    } else {
+      self.download(src, &cache_path).await
+    };
+
+    drop(permit);
+    self.release_semaphore(src, semaphore).await;
+    result.map(|()| cache_path)
+  }
+
+  async fn semaphore_for(&self, src: &Url) -> Arc<Semaphore> {
+    let mut inflight = self.inflight.lock().await;
+    inflight
+      .entry(src.clone())
+      .or_insert_with(|| Arc::new(Semaphore::new(1)))
+      .clone()
+  }
+
+  /// Drops the per-URL semaphore from `inflight` once nothing else is
+  /// waiting on it, so the map doesn't grow for the app's whole lifetime.
+  async fn release_semaphore(&self, src: &Url, semaphore: Arc<Semaphore>) {
+    let mut inflight = self.inflight.lock().await;
+    if let Some(current) = inflight.get(src) {
+      // `inflight`'s entry plus our own `semaphore` handle account for 2;
+      // any more means another task is still waiting on this URL.
+      if Arc::ptr_eq(current, &semaphore) && Arc::strong_count(&semaphore) <= 2 {
+        inflight.remove(src);
+      }
+    }
+  }
+
+  fn cache_path(&self, src: &Url) -> PathBuf {
+    let hash = format!("{:x}", md5::compute(src.to_string()));
+    let extension = Path::new(src.path())
+      .extension()
+      .and_then(|ext| ext.to_str())
+      .unwrap_or("bin");
+    self.cache_dir.join(format!("{hash}.{extension}"))
+  }
+
+  async fn download(&self, src: &Url, cache_path: &Path) -> Result<(), String> {
+    tokio::fs::create_dir_all(&self.cache_dir)
+      .await
+      .map_err(|error| format!("failed to create asset cache dir: {error}"))?;
+
+    let response = self
+      .client
+      .get(src.clone())
+      .send()
+      .await
+      .map_err(|error| format!("failed to fetch asset: {error}"))?
+      .error_for_status()
+      .map_err(|error| format!("failed to fetch asset: {error}"))?;
+
+    let bytes = response
+      .bytes()
+      .await
+      .map_err(|error| format!("failed to read asset body: {error}"))?;
+
+    // Write to a temp path first and rename into place so `cache_path` never
+    // becomes visible to the `exists()` fast path in `proxy` half-written.
+    let tmp_path = cache_path.with_extension(format!(
+      "{}.tmp",
+      cache_path.extension().and_then(|ext| ext.to_str()).unwrap_or("bin")
+    ));
+
+    tokio::fs::write(&tmp_path, bytes)
+      .await
+      .map_err(|error| format!("failed to write cached asset: {error}"))?;
+
+    tokio::fs::rename(&tmp_path, cache_path)
+      .await
+      .map_err(|error| format!("failed to finalize cached asset: {error}"))
+  }
+}
+
+/// Resolves a remote image URL to a local cached file so the frontend can
+/// composite it without re-downloading on every render.
+#[tauri::command]
+pub async fn cache_remote_asset(
+  proxy: tauri::State<'_, Proxy>,
+  url: String,
+) -> Result<String, String> {
+  let src = Url::parse(&url).map_err(|error| format!("invalid asset url: {error}"))?;
+  let path = proxy.proxy(&src).await?;
+  Ok(path.to_string_lossy().into_owned())
+}
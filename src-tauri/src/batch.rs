@@ -0,0 +1,75 @@
+use serde::Serialize;
+use tauri::Emitter;
+
+use crate::export::{export_to_path, ExportOptions};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ExportJob {
+  pub data_url: String,
+  pub path: String,
+  pub options: ExportOptions,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ExportProgress {
+  index: usize,
+  total: usize,
+  path: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ExportError {
+  index: usize,
+  total: usize,
+  path: String,
+  error: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ExportComplete {
+  total: usize,
+  failed: usize,
+}
+
+/// Exports each job in `jobs` in turn off the main thread, emitting
+/// `export-progress` after every completed item and `export-error` for
+/// items that fail, so the frontend can drive a progress bar instead of the
+/// window freezing on one big synchronous write. A failing job does not
+/// abort the remaining batch.
+#[tauri::command(async)]
+pub async fn batch_export(jobs: Vec<ExportJob>, window: tauri::Window) -> Result<(), String> {
+  let total = jobs.len();
+  let mut failed = 0;
+
+  for (index, job) in jobs.into_iter().enumerate() {
+    let path = job.path.clone();
+
+    let result = tauri::async_runtime::spawn_blocking(move || {
+      export_to_path(&job.data_url, &job.path, &job.options)
+    })
+    .await
+    .unwrap_or_else(|error| Err(format!("export task panicked: {error}")));
+
+    match result {
+      Ok(()) => {
+        let _ = window.emit("export-progress", ExportProgress { index, total, path });
+      }
+      Err(error) => {
+        failed += 1;
+        let _ = window.emit(
+          "export-error",
+          ExportError {
+            index,
+            total,
+            path,
+            error,
+          },
+        );
+      }
+    }
+  }
+
+  window
+    .emit("export-complete", ExportComplete { total, failed })
+    .map_err(|error| format!("failed to emit export-complete: {error}"))
+}
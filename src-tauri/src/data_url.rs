@@ -0,0 +1,12 @@
+use base64::{engine::general_purpose, Engine as _};
+
+/// Decodes a `data:<mime>;base64,<...>` string into its raw bytes.
+pub fn decode_data_url(data_url: &str) -> Result<Vec<u8>, String> {
+  let (_, encoded_image) = data_url
+    .split_once(',')
+    .ok_or_else(|| "invalid image data url".to_string())?;
+
+  general_purpose::STANDARD
+    .decode(encoded_image)
+    .map_err(|error| format!("failed to decode image data: {error}"))
+}
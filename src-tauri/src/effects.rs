@@ -0,0 +1,42 @@
+use base64::{engine::general_purpose, Engine as _};
+use image::ImageFormat;
+
+use crate::data_url::decode_data_url;
+
+/// A backdrop effect applied in Rust instead of via CSS/canvas filters, which
+/// get slow on large covers.
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+#[serde(tag = "type")]
+pub enum Effect {
+  Blur { sigma: f32 },
+  Brightness { value: i32 },
+  Grayscale,
+  Contrast { value: f32 },
+}
+
+/// Decodes `data_url`, applies `effect`, and returns a new PNG data URL for
+/// the frontend to render in a live preview.
+#[tauri::command]
+pub fn apply_image_effect(data_url: String, effect: Effect) -> Result<String, String> {
+  let image_bytes = decode_data_url(&data_url)?;
+
+  let image = image::load_from_memory(&image_bytes)
+    .map_err(|error| format!("failed to decode image: {error}"))?;
+
+  let image = match effect {
+    Effect::Blur { sigma } => image.blur(sigma),
+    Effect::Brightness { value } => image.brighten(value),
+    Effect::Grayscale => image.grayscale(),
+    Effect::Contrast { value } => image.adjust_contrast(value),
+  };
+
+  let mut bytes: Vec<u8> = Vec::new();
+  image
+    .write_to(&mut std::io::Cursor::new(&mut bytes), ImageFormat::Png)
+    .map_err(|error| format!("failed to encode image: {error}"))?;
+
+  Ok(format!(
+    "data:image/png;base64,{}",
+    general_purpose::STANDARD.encode(bytes)
+  ))
+}
@@ -0,0 +1,240 @@
+use std::path::Path;
+
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageFormat, Rgba};
+
+use crate::data_url::decode_data_url;
+
+/// Target codec for an exported cover, mirroring the choices surfaced in the
+/// export dialog on the frontend.
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+#[serde(tag = "type")]
+pub enum Format {
+  Png,
+  Jpeg { quality: u8 },
+  Webp { quality: u8 },
+  Avif,
+}
+
+/// Resampling filter used when `resize` is set, matching `image::imageops::FilterType`.
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+pub enum ResizeFilter {
+  Nearest,
+  Triangle,
+  CatmullRom,
+  Gaussian,
+  Lanczos3,
+}
+
+impl From<ResizeFilter> for FilterType {
+  fn from(filter: ResizeFilter) -> Self {
+    match filter {
+      ResizeFilter::Nearest => FilterType::Nearest,
+      ResizeFilter::Triangle => FilterType::Triangle,
+      ResizeFilter::CatmullRom => FilterType::CatmullRom,
+      ResizeFilter::Gaussian => FilterType::Gaussian,
+      ResizeFilter::Lanczos3 => FilterType::Lanczos3,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+pub struct Resize {
+  pub width: u32,
+  pub height: u32,
+  pub filter: ResizeFilter,
+}
+
+/// A background color used to flatten transparency. Applied whenever it's
+/// set, regardless of target format; formats with no alpha channel (e.g.
+/// JPEG) flatten to a default white when no background is given.
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+pub struct Background {
+  pub r: u8,
+  pub g: u8,
+  pub b: u8,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ExportOptions {
+  pub format: Format,
+  pub resize: Option<Resize>,
+  pub background: Option<Background>,
+}
+
+/// Decodes `data_url`, applies the requested resize and background flatten,
+/// re-encodes to `options.format`, and writes the result to `path`.
+#[tauri::command]
+pub fn export_image(path: String, data_url: String, options: ExportOptions) -> Result<(), String> {
+  export_to_path(&data_url, &path, &options)
+}
+
+/// Shared implementation behind [`export_image`] and `batch_export` so both
+/// commands decode/resize/encode a cover the same way.
+pub fn export_to_path(data_url: &str, path: &str, options: &ExportOptions) -> Result<(), String> {
+  let image_bytes = decode_data_url(data_url)?;
+
+  let image = image::load_from_memory(&image_bytes)
+    .map_err(|error| format!("failed to decode image: {error}"))?;
+
+  let image = apply_export_options(image, options);
+
+  let (_, bytes) = encode(image, options.format)?;
+
+  std::fs::write(path, bytes).map_err(|error| format!("failed to save image: {error}"))
+}
+
+fn apply_export_options(image: DynamicImage, options: &ExportOptions) -> DynamicImage {
+  let image = match options.resize {
+    Some(resize) => image.resize_exact(resize.width, resize.height, resize.filter.into()),
+    None => image,
+  };
+
+  match options.background {
+    // An explicit background flattens transparency regardless of format.
+    Some(background) => flatten(image, background),
+    // Formats with no alpha channel still need a default fill.
+    None if needs_flatten(options.format) => flatten(image, Background { r: 255, g: 255, b: 255 }),
+    None => image,
+  }
+}
+
+fn needs_flatten(format: Format) -> bool {
+  matches!(format, Format::Jpeg { .. })
+}
+
+fn flatten(image: DynamicImage, background: Background) -> DynamicImage {
+  let mut canvas = image::RgbaImage::from_pixel(
+    image.width(),
+    image.height(),
+    Rgba([background.r, background.g, background.b, 255]),
+  );
+  image::imageops::overlay(&mut canvas, &image.to_rgba8(), 0, 0);
+  DynamicImage::ImageRgba8(canvas)
+}
+
+/// Re-encodes `image` into the bytes for `format`, returning the
+/// `image::ImageFormat` used (for callers that need to pick a file
+/// extension) alongside the encoded bytes.
+pub fn encode(image: DynamicImage, format: Format) -> Result<(ImageFormat, Vec<u8>), String> {
+  let mut bytes: Vec<u8> = Vec::new();
+
+  match format {
+    Format::Png => {
+      image
+        .write_to(&mut std::io::Cursor::new(&mut bytes), ImageFormat::Png)
+        .map_err(|error| format!("failed to encode png: {error}"))?;
+      Ok((ImageFormat::Png, bytes))
+    }
+    Format::Jpeg { quality } => {
+      let encoder =
+        image::codecs::jpeg::JpegEncoder::new_with_quality(&mut bytes, quality);
+      image
+        .write_with_encoder(encoder)
+        .map_err(|error| format!("failed to encode jpeg: {error}"))?;
+      Ok((ImageFormat::Jpeg, bytes))
+    }
+    Format::Webp { quality } => {
+      // `image`'s native WebP path is lossless-only, so a lossy `quality`
+      // knob needs the `webp` crate (libwebp) instead.
+      let rgba = image.to_rgba8();
+      let encoder = webp::Encoder::from_rgba(&rgba, rgba.width(), rgba.height());
+      let encoded = encoder.encode(quality as f32);
+      bytes.extend_from_slice(&encoded);
+      Ok((ImageFormat::WebP, bytes))
+    }
+    Format::Avif => {
+      // Requires the `image` crate's `avif` feature (ravif/rav1e) enabled in
+      // Cargo.toml — without it this encode fails at runtime for every call.
+      image
+        .write_to(&mut std::io::Cursor::new(&mut bytes), ImageFormat::Avif)
+        .map_err(|error| format!("failed to encode avif: {error}"))?;
+      Ok((ImageFormat::Avif, bytes))
+    }
+  }
+}
+
+/// Decodes `data_url` once and, for each scale in `scales`, writes a resized
+/// copy next to `base_path` using the Apple `@2x`/`@3x` filename-stem
+/// convention (a scale of `1` is written as the bare `base_path`).
+#[tauri::command]
+pub fn export_retina_set(
+  data_url: String,
+  base_path: String,
+  scales: Vec<u32>,
+) -> Result<Vec<String>, String> {
+  let image_bytes = decode_data_url(&data_url)?;
+
+  let source = image::load_from_memory(&image_bytes)
+    .map_err(|error| format!("failed to decode image: {error}"))?;
+
+  let format = format_for_path(&base_path)?;
+  let base_width = source.width();
+  let base_height = source.height();
+
+  let mut written = Vec::with_capacity(scales.len());
+
+  for scale in scales {
+    let path = retina_path(&base_path, scale);
+
+    let scaled = if scale == 1 {
+      source.clone()
+    } else {
+      source.resize_exact(
+        base_width * scale,
+        base_height * scale,
+        FilterType::Lanczos3,
+      )
+    };
+
+    let (_, bytes) = encode(scaled, format)?;
+    std::fs::write(&path, bytes).map_err(|error| format!("failed to save image: {error}"))?;
+    written.push(path);
+  }
+
+  Ok(written)
+}
+
+/// Inserts the `@{scale}x` suffix before the extension, e.g. `cover.png` at
+/// scale `2` becomes `cover@2x.png`. Scale `1` is returned unchanged.
+fn retina_path(base_path: &str, scale: u32) -> String {
+  if scale <= 1 {
+    return base_path.to_string();
+  }
+
+  let path = Path::new(base_path);
+  let extension = path.extension().and_then(|ext| ext.to_str());
+  let stem = path
+    .file_stem()
+    .and_then(|stem| stem.to_str())
+    .unwrap_or(base_path);
+  let parent = path.parent().filter(|parent| !parent.as_os_str().is_empty());
+
+  let file_name = match extension {
+    Some(extension) => format!("{stem}@{scale}x.{extension}"),
+    None => format!("{stem}@{scale}x"),
+  };
+
+  match parent {
+    Some(parent) => parent.join(file_name).to_string_lossy().into_owned(),
+    None => file_name,
+  }
+}
+
+/// Infers the export `Format` from `path`'s extension, defaulting to PNG when
+/// the extension is missing. Unrecognized extensions return an error.
+fn format_for_path(path: &str) -> Result<Format, String> {
+  let extension = Path::new(path)
+    .extension()
+    .and_then(|ext| ext.to_str())
+    .unwrap_or("png")
+    .to_lowercase();
+
+  match extension.as_str() {
+    "png" => Ok(Format::Png),
+    "jpg" | "jpeg" => Ok(Format::Jpeg { quality: 90 }),
+    "webp" => Ok(Format::Webp { quality: 90 }),
+    "avif" => Ok(Format::Avif),
+    other => Err(format!("unsupported export extension: {other}")),
+  }
+}
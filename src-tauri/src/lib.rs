@@ -1,15 +1,20 @@
-use base64::{engine::general_purpose, Engine as _};
+use tauri::Manager;
 
-#[tauri::command]
-fn save_export_image(path: String, data_url: String) -> Result<(), String> {
-  let (_, encoded_image) = data_url
-    .split_once(',')
-    .ok_or_else(|| "invalid image data url".to_string())?;
+mod assets;
+mod batch;
+mod data_url;
+mod effects;
+mod export;
 
-  let image_bytes = general_purpose::STANDARD
-    .decode(encoded_image)
-    .map_err(|error| format!("failed to decode image data: {error}"))?;
+use assets::{cache_remote_asset, Proxy};
+use batch::batch_export;
+use data_url::decode_data_url;
+use effects::apply_image_effect;
+use export::{export_image, export_retina_set};
 
+#[tauri::command]
+fn save_export_image(path: String, data_url: String) -> Result<(), String> {
+  let image_bytes = decode_data_url(&data_url)?;
   std::fs::write(path, image_bytes).map_err(|error| format!("failed to save image: {error}"))
 }
 
@@ -25,9 +30,20 @@ pub fn run() {
             .build(),
         )?;
       }
+
+      let cache_dir = app.path().app_cache_dir()?.join("assets");
+      app.manage(Proxy::new(cache_dir));
+
       Ok(())
     })
-    .invoke_handler(tauri::generate_handler![save_export_image])
+    .invoke_handler(tauri::generate_handler![
+      save_export_image,
+      export_image,
+      cache_remote_asset,
+      apply_image_effect,
+      batch_export,
+      export_retina_set
+    ])
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
 }